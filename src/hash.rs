@@ -0,0 +1,213 @@
+//! Non-cryptographic `Hasher` implementations for hot lookup paths where
+//! SipHash's DoS resistance isn't needed.
+
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+
+/// FNV-1a, a fast non-cryptographic hash.
+pub struct FnvHasher(u64);
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut hash = self.0;
+        for &byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        self.0 = hash;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A [`BuildHasher`](std::hash::BuildHasher) that produces [`FnvHasher`]s.
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+/// Jenkins' one-at-a-time hash.
+#[derive(Default)]
+pub struct JenkinsHasher(u32);
+
+impl Hasher for JenkinsHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut hash = self.0;
+        for &byte in bytes {
+            hash = hash.wrapping_add(byte as u32);
+            hash = hash.wrapping_add(hash << 10);
+            hash ^= hash >> 6;
+        }
+        self.0 = hash;
+    }
+
+    fn finish(&self) -> u64 {
+        let mut hash = self.0;
+        hash = hash.wrapping_add(hash << 3);
+        hash ^= hash >> 11;
+        hash = hash.wrapping_add(hash << 15);
+        hash as u64
+    }
+}
+
+/// A [`BuildHasher`](std::hash::BuildHasher) that produces [`JenkinsHasher`]s.
+pub type JenkinsBuildHasher = BuildHasherDefault<JenkinsHasher>;
+
+/// A hasher whose output is stable across platforms, architectures, and Rust
+/// versions.
+///
+/// `DefaultHasher`'s output isn't guaranteed stable across Rust releases,
+/// and the default `Hasher::write_u*` methods feed integers through
+/// `to_ne_bytes`, which varies by endianness. For consistent hashing across
+/// a distributed system, every node must compute the same ring position for
+/// the same key regardless of platform, so `StableHasher` uses a fixed-seed
+/// FNV-1a and always encodes integers as fixed little-endian bytes. FNV-1a's
+/// raw state is finalized with a 64-bit avalanche mix (as used by
+/// MurmurHash3) before being returned, since similar inputs like `"node#0"`
+/// and `"node#1"` otherwise produce ring positions too correlated for even
+/// placement.
+pub struct StableHasher(u64);
+
+fn fmix64(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    h ^= h >> 33;
+    h
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(FNV_OFFSET_BASIS)
+    }
+}
+
+impl StableHasher {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        let mut hash = self.0;
+        for &byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        self.0 = hash;
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.write_bytes(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_bytes(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_bytes(&(i as u64).to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        fmix64(self.0)
+    }
+}
+
+/// A [`BuildHasher`](std::hash::BuildHasher) that produces [`StableHasher`]s.
+pub type StableBuildHasher = BuildHasherDefault<StableHasher>;
+
+/// Hashes arbitrary bytes down to a `u64` using [`StableHasher`], so the
+/// result is reproducible across machines and architectures.
+///
+/// This hashes `bytes` through the same [`Hash`] trait path as
+/// [`BuildHasher::hash_one`](std::hash::BuildHasher::hash_one), which is
+/// what [`ConsistentHashRing`](crate::ConsistentHashRing) uses internally
+/// when built with the default [`StableBuildHasher`] -- so this function's
+/// output always matches the ring's actual placement hash.
+pub fn stable_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = StableHasher::default();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv_is_deterministic_and_avalanches() {
+        let mut a = FnvHasher::default();
+        a.write(b"user_1");
+        let mut b = FnvHasher::default();
+        b.write(b"user_1");
+        assert_eq!(a.finish(), b.finish());
+
+        let mut c = FnvHasher::default();
+        c.write(b"user_2");
+        assert_ne!(a.finish(), c.finish());
+    }
+
+    #[test]
+    fn jenkins_is_deterministic_and_avalanches() {
+        let mut a = JenkinsHasher::default();
+        a.write(b"user_1");
+        let mut b = JenkinsHasher::default();
+        b.write(b"user_1");
+        assert_eq!(a.finish(), b.finish());
+
+        let mut c = JenkinsHasher::default();
+        c.write(b"user_2");
+        assert_ne!(a.finish(), c.finish());
+    }
+
+    // Pinned to this implementation's own output (not an external spec) so
+    // the same byte input always produces the same ring position on every
+    // platform; a regression here means StableHasher's output changed, not
+    // necessarily that it's wrong.
+    #[test]
+    fn stable_hasher_matches_known_constants() {
+        let mut empty = StableHasher::default();
+        empty.write(b"");
+        assert_eq!(empty.finish(), 0xefd01f60ba992926);
+
+        let mut a = StableHasher::default();
+        a.write(b"a");
+        assert_eq!(a.finish(), 0x82a2a958a9bece5b);
+
+        let mut hello = StableHasher::default();
+        hello.write(b"hello");
+        assert_eq!(hello.finish(), 0xe9c562c0fdb23244);
+    }
+
+    // Pinned so a regression back to `to_ne_bytes` (which matches this
+    // little-endian constant only on LE targets) would fail this test on a
+    // big-endian target, unlike a bare self-consistency check.
+    #[test]
+    fn stable_hasher_write_u64_is_pinned_to_little_endian_encoding() {
+        let mut hasher = StableHasher::default();
+        hasher.write_u64(0x0102030405060708);
+        assert_eq!(hasher.finish(), 0x4ce83454b8ce0827);
+    }
+}
@@ -0,0 +1,14 @@
+//! A small consistent-hashing toolkit.
+
+mod hash;
+mod placement;
+mod ring;
+mod strategy;
+
+pub use hash::{
+    stable_hash, FnvBuildHasher, FnvHasher, JenkinsBuildHasher, JenkinsHasher, StableBuildHasher,
+    StableHasher,
+};
+pub use placement::{jump_hash, JumpHash, Placement, Rendezvous};
+pub use ring::{hash_key, ConsistentHashRing, Node};
+pub use strategy::Strategy;
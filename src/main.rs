@@ -0,0 +1,32 @@
+use consistent_hashing::{hash_key, ConsistentHashRing, Node};
+
+#[derive(Clone)]
+struct Server(String);
+
+impl Node for Server {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+}
+
+fn main() {
+    let key1 = "user_1";
+    let key2 = "user_2"; // Only one character difference
+
+    let h1 = hash_key(key1.as_bytes());
+    let h2 = hash_key(key2.as_bytes());
+
+    println!("Hash 1: {:x}", h1); // 50c4389d07dfc2f9
+    println!("Hash 2: {:x}", h2); // d56a97001fa9c02f
+    // Despite similar inputs, the outputs share zero patterns.
+
+    let mut ring = ConsistentHashRing::new();
+    ring.add_node(Server("10.0.0.1:6379".to_string()), 160);
+    ring.add_node(Server("10.0.0.2:6379".to_string()), 160);
+
+    for key in [key1, key2] {
+        if let Some(node) = ring.get_node(key.as_bytes()) {
+            println!("{key} -> {}", node.name());
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{stable_hash, Node};
+
+/// A memory-free strategy for placing a key onto one of a fixed slice of
+/// nodes, without the ring's `O(replicas * nodes)` storage or `BTreeMap`
+/// lookups. Implementations hash through [`crate::stable_hash`] so
+/// placement stays reproducible across machines, the same requirement the
+/// ring's [`crate::StableHasher`] exists for.
+pub trait Placement<N: Node> {
+    /// Chooses the node responsible for `key` out of `nodes`.
+    fn place<'a>(&self, key: &[u8], nodes: &'a [N]) -> Option<&'a N>;
+}
+
+/// Jump Consistent Hash (Lamping & Veach): near-perfect balance and minimal
+/// key movement as `num_buckets` grows, with no per-node state at all.
+pub fn jump_hash(mut key: u64, num_buckets: i32) -> i32 {
+    let mut b: i64 = -1;
+    let mut j: i64 = 0;
+    while j < num_buckets as i64 {
+        b = j;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        j = ((b + 1) as f64 * (2f64.powi(31) / (((key >> 33) + 1) as f64))) as i64;
+    }
+    b as i32
+}
+
+/// [`Placement`] strategy backed by [`jump_hash`].
+pub struct JumpHash;
+
+impl<N: Node> Placement<N> for JumpHash {
+    fn place<'a>(&self, key: &[u8], nodes: &'a [N]) -> Option<&'a N> {
+        if nodes.is_empty() {
+            return None;
+        }
+        let index = jump_hash(stable_hash(key), nodes.len() as i32);
+        nodes.get(index as usize)
+    }
+}
+
+/// Rendezvous (highest random weight) hashing: for each candidate node,
+/// compute a score from `hash(key || node_name)` and the node's
+/// [`Node::weight`], and pick the node with the maximum score. Unlike the
+/// ring or jump hash, this supports an arbitrary, unordered node set and
+/// weighting without any shared state.
+pub struct Rendezvous;
+
+impl<N: Node> Placement<N> for Rendezvous {
+    fn place<'a>(&self, key: &[u8], nodes: &'a [N]) -> Option<&'a N> {
+        nodes
+            .iter()
+            .max_by(|a, b| hrw_score(key, *a).total_cmp(&hrw_score(key, *b)))
+    }
+}
+
+/// The standard weighted highest-random-weight score: `-weight / ln(h)`,
+/// where `h` is the key/node hash normalized into the open interval
+/// `(0, 1)`. Larger weights push the score up, so weighted nodes win more
+/// keys proportionally to their weight.
+fn hrw_score<N: Node>(key: &[u8], node: &N) -> f64 {
+    let mut combined = key.to_vec();
+    combined.extend_from_slice(node.name().as_bytes());
+    let hash = stable_hash(&combined);
+    // Keep the top 52 bits so `+ 0.5` is exact and the result stays below 1.
+    let normalized = ((hash >> 12) as f64 + 0.5) / (1u64 << 52) as f64;
+    -node.weight() / normalized.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestNode(String);
+
+    impl Node for TestNode {
+        fn name(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    #[derive(Clone)]
+    struct WeightedNode {
+        name: String,
+        weight: f64,
+    }
+
+    impl Node for WeightedNode {
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn weight(&self) -> f64 {
+            self.weight
+        }
+    }
+
+    #[test]
+    fn jump_hash_is_deterministic_and_in_range() {
+        for num_buckets in [1, 2, 10, 100] {
+            let bucket = jump_hash(0x1234_5678_9abc_def0, num_buckets);
+            assert!((0..num_buckets).contains(&bucket));
+            assert_eq!(bucket, jump_hash(0x1234_5678_9abc_def0, num_buckets));
+        }
+    }
+
+    #[test]
+    fn jump_hash_minimizes_movement_when_buckets_grow() {
+        let key = 0x1234_5678_9abc_def0;
+        let before = jump_hash(key, 10);
+        let after = jump_hash(key, 11);
+        // Growing by one bucket either keeps a key in place or moves it to
+        // the newly added bucket -- never to an unrelated existing bucket.
+        assert!(after == before || after == 10);
+    }
+
+    #[test]
+    fn rendezvous_picks_a_consistent_node_for_the_same_key() {
+        let nodes = vec![
+            TestNode("a".to_string()),
+            TestNode("b".to_string()),
+            TestNode("c".to_string()),
+        ];
+        let first = Rendezvous.place(b"some-key", &nodes).unwrap().name();
+        let second = Rendezvous.place(b"some-key", &nodes).unwrap().name();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn rendezvous_only_remaps_keys_that_pointed_at_the_removed_node() {
+        let nodes = vec![
+            TestNode("a".to_string()),
+            TestNode("b".to_string()),
+            TestNode("c".to_string()),
+        ];
+        let reduced = vec![nodes[0].clone(), nodes[1].clone()];
+
+        for i in 0..100 {
+            let key = format!("key-{i}");
+            let before = Rendezvous.place(key.as_bytes(), &nodes).unwrap().name();
+            let after = Rendezvous.place(key.as_bytes(), &reduced).unwrap().name();
+            if before != "c" {
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    #[test]
+    fn rendezvous_routes_more_keys_to_a_heavier_node() {
+        let nodes = vec![
+            WeightedNode {
+                name: "light".to_string(),
+                weight: 1.0,
+            },
+            WeightedNode {
+                name: "heavy".to_string(),
+                weight: 9.0,
+            },
+        ];
+
+        let mut heavy_count = 0;
+        const NUM_KEYS: usize = 1_000;
+        for i in 0..NUM_KEYS {
+            let key = format!("key-{i}");
+            if Rendezvous.place(key.as_bytes(), &nodes).unwrap().name() == "heavy" {
+                heavy_count += 1;
+            }
+        }
+
+        // With a 9x weight, "heavy" should win the large majority of keys.
+        assert!(
+            heavy_count > NUM_KEYS * 3 / 4,
+            "heavy node only won {heavy_count}/{NUM_KEYS} keys"
+        );
+    }
+}
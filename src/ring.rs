@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use crate::{stable_hash, StableBuildHasher};
+
+/// Something that can be placed on a [`ConsistentHashRing`].
+pub trait Node {
+    /// A stable identifier for this node, used to compute its ring position.
+    fn name(&self) -> String;
+
+    /// Relative weight used by weighted placement strategies (e.g.
+    /// [`crate::Rendezvous`]). Defaults to `1.0` for unweighted nodes; give
+    /// a heavier node a larger weight to route it proportionally more keys.
+    fn weight(&self) -> f64 {
+        1.0
+    }
+}
+
+/// Hashes arbitrary bytes down to a `u64` ring position.
+///
+/// Delegates to [`stable_hash`], so this always matches the placement hash
+/// a default-configured [`ConsistentHashRing`] computes internally.
+pub fn hash_key(bytes: &[u8]) -> u64 {
+    stable_hash(bytes)
+}
+
+/// A consistent-hashing ring that maps keys to nodes.
+///
+/// Each node is placed at `replicas` distinct positions on a ring of `u64`
+/// hash values (virtual nodes), rather than a single position, which spreads
+/// load far more evenly than a single hash point would. A key is routed to
+/// the node at the first position greater than or equal to the key's hash,
+/// wrapping around to the first node if none is greater. This means adding
+/// or removing a node only remaps the keys that fell between its
+/// neighbours, roughly `K / n` of the total keyspace.
+pub struct ConsistentHashRing<N, S = StableBuildHasher> {
+    ring: BTreeMap<u64, N>,
+    replicas: HashMap<String, usize>,
+    hash_builder: S,
+    loads: HashMap<String, usize>,
+    assignments: HashMap<Vec<u8>, u64>,
+    total_assigned: usize,
+}
+
+impl<N: Node + Clone> ConsistentHashRing<N, StableBuildHasher> {
+    /// Creates an empty ring using [`crate::StableHasher`], so ring positions
+    /// are reproducible across machines and architectures.
+    pub fn new() -> Self {
+        Self::with_hasher(StableBuildHasher::default())
+    }
+}
+
+impl<N: Node + Clone> Default for ConsistentHashRing<N, StableBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Node + Clone, S: BuildHasher> ConsistentHashRing<N, S> {
+    /// Creates an empty ring using a custom `BuildHasher`.
+    ///
+    /// Use this to place nodes with a hasher other than the default
+    /// [`crate::StableBuildHasher`], such as [`crate::FnvBuildHasher`],
+    /// [`crate::JenkinsBuildHasher`], or SipHash via
+    /// [`std::collections::hash_map::RandomState`]; ring positions then
+    /// follow that hasher's output.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            ring: BTreeMap::new(),
+            replicas: HashMap::new(),
+            hash_builder,
+            loads: HashMap::new(),
+            assignments: HashMap::new(),
+            total_assigned: 0,
+        }
+    }
+
+    fn hash(&self, bytes: &[u8]) -> u64 {
+        self.hash_builder.hash_one(bytes)
+    }
+
+    /// Adds `node` to the ring, placed at `replicas` virtual-node positions.
+    ///
+    /// Give heavier nodes proportionally more replicas to weight them with
+    /// a larger share of the keyspace.
+    pub fn add_node(&mut self, node: N, replicas: usize) {
+        let name = node.name();
+        for i in 0..replicas {
+            let position = self.hash(format!("{name}#{i}").as_bytes());
+            self.ring.insert(position, node.clone());
+        }
+        self.replicas.insert(name, replicas);
+    }
+
+    /// Removes `node` from the ring.
+    pub fn remove_node(&mut self, node: &N) {
+        let name = node.name();
+        let replicas = self.replicas.remove(&name).unwrap_or(0);
+        for i in 0..replicas {
+            let position = self.hash(format!("{name}#{i}").as_bytes());
+            self.ring.remove(&position);
+        }
+    }
+
+    /// Looks up the node responsible for `key`.
+    pub fn get_node(&self, key: &[u8]) -> Option<&N> {
+        if self.ring.is_empty() {
+            return None;
+        }
+        let hash = self.hash(key);
+        self.ring
+            .range(hash..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, node)| node)
+    }
+
+    /// Looks up the node responsible for `key`, bounding how many keys any
+    /// single node may hold.
+    ///
+    /// This is Google's "consistent hashing with bounded loads": no node is
+    /// allowed to exceed a capacity of `⌈(total + 1) / n · c⌉` keys, where
+    /// `n` is the number of distinct nodes and `total` is the number of
+    /// currently assigned keys. Starting at the first ring position `>=
+    /// hash(key)`, this walks clockwise and assigns `key` to the first node
+    /// under capacity, which caps hotspot load while preserving the ring's
+    /// minimal-remapping property. Call [`Self::remove_key`] once a key is
+    /// no longer live so its slot is released; calling this again for a key
+    /// that's still live returns its existing assignment instead of
+    /// double-counting its load.
+    pub fn get_node_bounded(&mut self, key: &[u8], c: f64) -> Option<&N> {
+        if let Some(position) = self.assignments.get(key) {
+            return self.ring.get(position);
+        }
+        if self.ring.is_empty() {
+            return None;
+        }
+        let num_nodes = self.replicas.len();
+        if num_nodes == 0 {
+            return None;
+        }
+        let capacity = ((self.total_assigned + 1) as f64 / num_nodes as f64 * c).ceil() as usize;
+
+        let hash = self.hash(key);
+        let chosen = self
+            .ring
+            .range(hash..)
+            .chain(self.ring.range(..hash))
+            .map(|(pos, _)| *pos)
+            .find(|pos| {
+                let name = self.ring[pos].name();
+                self.loads.get(&name).copied().unwrap_or(0) < capacity
+            })?;
+
+        let name = self.ring[&chosen].name();
+        *self.loads.entry(name).or_insert(0) += 1;
+        self.assignments.insert(key.to_vec(), chosen);
+        self.total_assigned += 1;
+        self.ring.get(&chosen)
+    }
+
+    /// Releases `key`'s slot assigned by [`Self::get_node_bounded`], so a
+    /// later lookup for the same key can be reassigned elsewhere.
+    pub fn remove_key(&mut self, key: &[u8]) {
+        if let Some(position) = self.assignments.remove(key) {
+            if let Some(name) = self.ring.get(&position).map(Node::name) {
+                if let Some(load) = self.loads.get_mut(&name) {
+                    *load = load.saturating_sub(1);
+                }
+            }
+            self.total_assigned = self.total_assigned.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap as StdHashMap;
+
+    #[derive(Clone)]
+    struct TestNode(String);
+
+    impl Node for TestNode {
+        fn name(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn distributes_keys_within_10_percent_of_uniform() {
+        const NUM_NODES: usize = 5;
+        const REPLICAS: usize = 160;
+        const NUM_KEYS: usize = 100_000;
+
+        let mut ring = ConsistentHashRing::new();
+        for i in 0..NUM_NODES {
+            ring.add_node(TestNode(format!("10.0.0.{i}:6379")), REPLICAS);
+        }
+
+        let mut counts: StdHashMap<String, usize> = StdHashMap::new();
+        for i in 0..NUM_KEYS {
+            let key = format!("key-{i}");
+            let node = ring.get_node(key.as_bytes()).expect("ring is non-empty");
+            *counts.entry(node.name()).or_insert(0) += 1;
+        }
+
+        let expected = NUM_KEYS / NUM_NODES;
+        let tolerance = expected / 10;
+        assert_eq!(counts.len(), NUM_NODES);
+        for (name, count) in &counts {
+            assert!(
+                count.abs_diff(expected) <= tolerance,
+                "node {name} got {count} keys, expected {expected} +/- {tolerance}"
+            );
+        }
+    }
+
+    #[test]
+    fn bounded_loads_caps_per_node_overflow() {
+        const NUM_NODES: usize = 4;
+        const NUM_KEYS: usize = 1_000;
+        const C: f64 = 1.25;
+
+        let mut ring = ConsistentHashRing::new();
+        for i in 0..NUM_NODES {
+            ring.add_node(TestNode(format!("10.0.0.{i}:6379")), 160);
+        }
+
+        let mut counts: StdHashMap<String, usize> = StdHashMap::new();
+        for i in 0..NUM_KEYS {
+            let key = format!("key-{i}");
+            let node = ring
+                .get_node_bounded(key.as_bytes(), C)
+                .expect("ring is non-empty")
+                .name();
+            *counts.entry(node).or_insert(0) += 1;
+        }
+
+        let max_capacity = ((NUM_KEYS as f64 / NUM_NODES as f64) * C).ceil() as usize;
+        for (name, count) in &counts {
+            assert!(
+                *count <= max_capacity,
+                "node {name} got {count} keys, over capacity {max_capacity}"
+            );
+        }
+    }
+
+    #[test]
+    fn remove_key_releases_its_slot() {
+        let mut ring = ConsistentHashRing::new();
+        ring.add_node(TestNode("a".to_string()), 160);
+        ring.add_node(TestNode("b".to_string()), 160);
+
+        let key = b"some-key";
+        let assigned = ring.get_node_bounded(key, 1.25).unwrap().name();
+        ring.remove_key(key);
+
+        assert_eq!(ring.total_assigned, 0);
+        assert_eq!(ring.loads.get(&assigned).copied().unwrap_or(0), 0);
+    }
+
+    #[test]
+    fn get_node_bounded_is_idempotent_for_a_still_live_key() {
+        let mut ring = ConsistentHashRing::new();
+        ring.add_node(TestNode("a".to_string()), 160);
+        ring.add_node(TestNode("b".to_string()), 160);
+
+        let key = b"some-key";
+        let first = ring.get_node_bounded(key, 1.25).unwrap().name();
+        let second = ring.get_node_bounded(key, 1.25).unwrap().name();
+
+        assert_eq!(first, second);
+        assert_eq!(ring.total_assigned, 1);
+        assert_eq!(ring.loads.get(&first).copied().unwrap_or(0), 1);
+    }
+}
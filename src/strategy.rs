@@ -0,0 +1,67 @@
+use crate::{ConsistentHashRing, JumpHash, Node, Placement, Rendezvous, StableBuildHasher};
+
+/// Chooses among the ring, jump hash, and HRW placement strategies behind a
+/// single lookup API, so callers can swap the strategy without changing
+/// call sites.
+pub enum Strategy<N> {
+    /// Sorted-ring placement via [`ConsistentHashRing`], using virtual nodes
+    /// for even load and minimal remapping.
+    Ring(ConsistentHashRing<N, StableBuildHasher>),
+    /// Jump Consistent Hash over a fixed node list, with no per-node state.
+    Jump(Vec<N>),
+    /// Rendezvous (HRW) hashing over a fixed node list, supporting
+    /// arbitrary node sets and weighting without a ring.
+    Hrw(Vec<N>),
+}
+
+impl<N: Node + Clone> Strategy<N> {
+    /// Looks up the node responsible for `key`, regardless of which
+    /// strategy variant is in use.
+    pub fn get_node(&self, key: &[u8]) -> Option<&N> {
+        match self {
+            Strategy::Ring(ring) => ring.get_node(key),
+            Strategy::Jump(nodes) => JumpHash.place(key, nodes),
+            Strategy::Hrw(nodes) => Rendezvous.place(key, nodes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestNode(String);
+
+    impl Node for TestNode {
+        fn name(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    fn nodes() -> Vec<TestNode> {
+        vec![
+            TestNode("a".to_string()),
+            TestNode("b".to_string()),
+            TestNode("c".to_string()),
+        ]
+    }
+
+    #[test]
+    fn every_strategy_answers_get_node() {
+        let mut ring = ConsistentHashRing::new();
+        for node in nodes() {
+            ring.add_node(node, 160);
+        }
+
+        let strategies = [
+            Strategy::Ring(ring),
+            Strategy::Jump(nodes()),
+            Strategy::Hrw(nodes()),
+        ];
+
+        for strategy in &strategies {
+            assert!(strategy.get_node(b"some-key").is_some());
+        }
+    }
+}